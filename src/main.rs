@@ -1,29 +1,471 @@
 use lazy_static::lazy_static;
 use rdev::{display_size, grab, simulate, Button, Event, EventType, Key};
-use std::{collections::HashMap, thread, time};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{mpsc, Mutex},
+    thread, time,
+};
 
 #[cfg(target_os = "macos")]
 use core_graphics::event::CGEvent;
 #[cfg(target_os = "macos")]
 use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
 
-const SLOW_SPEED: f64 = 5.0;
-const FAST_SPEED: f64 = 40.0;
-const ULTRA_FAST_SPEED: f64 = 150.0;
+// Default speed/scroll tunables, used whenever `~/.config/vimouse/config.toml`
+// is absent or doesn't override them. See the `config` module.
+const DEFAULT_SLOW_SPEED: f64 = 5.0;
+const DEFAULT_ULTRA_FAST_SPEED: f64 = 150.0;
+const DEFAULT_SCROLL_INITIAL_VELOCITY: f64 = 20.0;
+const DEFAULT_SCROLL_DECELERATION: f64 = 0.85; // Momentum decay factor
+const DEFAULT_SCROLL_MIN_VELOCITY: f64 = 0.5; // Minimum velocity before stopping
+const DEFAULT_SCROLL_FRAME_DELAY_MS: u64 = 16; // ~60 FPS for smooth animation
 
-// Smooth scroll constants
-const SCROLL_INITIAL_VELOCITY: f64 = 20.0;
-const SCROLL_DECELERATION: f64 = 0.85; // Momentum decay factor
-const SCROLL_MIN_VELOCITY: f64 = 0.5; // Minimum velocity before stopping
-const SCROLL_FRAME_DELAY_MS: u64 = 16; // ~60 FPS for smooth animation
+// Cursor movement momentum: how long a held direction key takes to ramp
+// from the slow speed up to its ceiling, in milliseconds.
+const MOVE_RAMP_MS: f64 = 300.0;
 
-static mut MOUSE_POSITION: (f64, f64) = (0., 0.);
-static mut MOUSE_SPEED: f64 = FAST_SPEED;
-static mut G_KEY_HELD: bool = false;
+/// User-configurable keybindings and tunables, loaded once at startup from
+/// `~/.config/vimouse/config.toml`. Any table or key the file doesn't
+/// mention keeps the hardcoded default, so an empty or missing file
+/// reproduces today's behavior exactly.
+mod config {
+    use super::*;
+    use serde::Deserialize;
+    use std::fs;
+
+    /// Single-key actions that aren't a movement direction or a jump-grid
+    /// cell - these are what the `[bindings]` table maps key names onto.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Action {
+        LeftClick,
+        RightClick,
+        EnterScrollMode,
+        ToggleScrollMode,
+        EnterHintMode,
+        EnterDragMode,
+        SlowSpeed,
+        UltraFastSpeed,
+        DetectElements,
+        Exit,
+    }
+
+    impl Action {
+        fn from_name(name: &str) -> Option<Action> {
+            Some(match name {
+                "left_click" => Action::LeftClick,
+                "right_click" => Action::RightClick,
+                "enter_scroll_mode" => Action::EnterScrollMode,
+                "toggle_scroll_mode" => Action::ToggleScrollMode,
+                "enter_hint_mode" => Action::EnterHintMode,
+                "enter_drag_mode" => Action::EnterDragMode,
+                "slow_speed" => Action::SlowSpeed,
+                "ultra_fast_speed" => Action::UltraFastSpeed,
+                "detect_elements" => Action::DetectElements,
+                "exit" => Action::Exit,
+                _ => return None,
+            })
+        }
+    }
+
+    pub struct Config {
+        pub movement: HashMap<Key, (f64, f64)>,
+        pub jump_grid: HashMap<Key, (f64, f64)>,
+        pub bindings: HashMap<Key, Action>,
+        pub slow_speed: f64,
+        pub ultra_fast_speed: f64,
+        pub scroll_initial_velocity: f64,
+        pub scroll_deceleration: f64,
+        pub scroll_min_velocity: f64,
+        pub scroll_frame_delay_ms: u64,
+    }
+
+    impl Default for Config {
+        fn default() -> Config {
+            Config {
+                movement: HashMap::from([
+                    (Key::KeyH, (-1., 0.)),
+                    (Key::KeyL, (1., 0.)),
+                    (Key::KeyJ, (0., 1.)),
+                    (Key::KeyK, (0., -1.)),
+                    (Key::KeyY, (-1., -1.)),
+                    (Key::KeyU, (1., -1.)),
+                    (Key::KeyB, (-1., 1.)),
+                    (Key::KeyN, (1., 1.)),
+                ]),
+                jump_grid: HashMap::from([
+                    (Key::KeyQ, (0., 0.)),
+                    (Key::KeyW, (1., 0.)),
+                    (Key::KeyE, (2., 0.)),
+                    (Key::KeyR, (3., 0.)),
+                    (Key::KeyA, (0., 1.)),
+                    (Key::KeyS, (1., 1.)),
+                    (Key::KeyD, (2., 1.)),
+                    (Key::KeyF, (3., 1.)),
+                    (Key::KeyZ, (0., 2.)),
+                    (Key::KeyX, (1., 2.)),
+                    (Key::KeyC, (2., 2.)),
+                    (Key::KeyV, (3., 2.)),
+                ]),
+                bindings: HashMap::from([
+                    (Key::Space, Action::LeftClick),
+                    (Key::ControlLeft, Action::RightClick),
+                    (Key::ControlRight, Action::RightClick),
+                    (Key::CapsLock, Action::RightClick),
+                    (Key::KeyG, Action::EnterScrollMode),
+                    (Key::KeyT, Action::ToggleScrollMode),
+                    (Key::KeyO, Action::EnterHintMode),
+                    (Key::KeyP, Action::EnterDragMode),
+                    (Key::ShiftLeft, Action::SlowSpeed),
+                    (Key::ShiftRight, Action::SlowSpeed),
+                    (Key::Alt, Action::UltraFastSpeed),
+                    (Key::KeyI, Action::DetectElements),
+                    (Key::Escape, Action::Exit),
+                ]),
+                slow_speed: DEFAULT_SLOW_SPEED,
+                ultra_fast_speed: DEFAULT_ULTRA_FAST_SPEED,
+                scroll_initial_velocity: DEFAULT_SCROLL_INITIAL_VELOCITY,
+                scroll_deceleration: DEFAULT_SCROLL_DECELERATION,
+                scroll_min_velocity: DEFAULT_SCROLL_MIN_VELOCITY,
+                scroll_frame_delay_ms: DEFAULT_SCROLL_FRAME_DELAY_MS,
+            }
+        }
+    }
+
+    impl Config {
+        /// Loads `~/.config/vimouse/config.toml` over the defaults. Any
+        /// problem reading or parsing the file - missing, unreadable,
+        /// malformed - falls back to the defaults rather than failing to
+        /// start, since the whole point is that the file is optional.
+        pub fn load() -> Config {
+            let Some(path) = config_path() else {
+                return Config::default();
+            };
+            let Ok(contents) = fs::read_to_string(&path) else {
+                return Config::default();
+            };
+            match toml::from_str::<RawConfig>(&contents) {
+                Ok(raw) => raw.into_config(),
+                Err(err) => {
+                    println!("Could not parse {}: {err}", path.display());
+                    println!("Falling back to the built-in keybindings and speeds.");
+                    Config::default()
+                }
+            }
+        }
+    }
+
+    fn config_path() -> Option<std::path::PathBuf> {
+        let home = std::env::var_os("HOME")?;
+        Some(std::path::PathBuf::from(home).join(".config/vimouse/config.toml"))
+    }
+
+    #[derive(Debug, Default, Deserialize)]
+    struct RawConfig {
+        #[serde(default)]
+        movement: HashMap<String, String>,
+        #[serde(default)]
+        jump_grid: HashMap<String, String>,
+        #[serde(default)]
+        bindings: HashMap<String, String>,
+        #[serde(default)]
+        speed: RawSpeed,
+        #[serde(default)]
+        scroll: RawScroll,
+    }
+
+    #[derive(Debug, Default, Deserialize)]
+    struct RawSpeed {
+        slow: Option<f64>,
+        ultra_fast: Option<f64>,
+    }
+
+    #[derive(Debug, Default, Deserialize)]
+    struct RawScroll {
+        initial_velocity: Option<f64>,
+        deceleration: Option<f64>,
+        min_velocity: Option<f64>,
+        frame_delay_ms: Option<u64>,
+    }
+
+    impl RawConfig {
+        fn into_config(self) -> Config {
+            let mut config = Config::default();
+
+            for (key_name, action_name) in &self.movement {
+                let (Some(key), Some(direction)) =
+                    (key_from_name(key_name), direction_from_name(action_name))
+                else {
+                    println!("Ignoring unknown [movement] entry: {key_name} = \"{action_name}\"");
+                    continue;
+                };
+                config.movement.insert(key, direction);
+            }
+
+            for (key_name, cell_name) in &self.jump_grid {
+                let (Some(key), Some(cell)) =
+                    (key_from_name(key_name), cell_from_name(cell_name))
+                else {
+                    println!("Ignoring unknown [jump_grid] entry: {key_name} = \"{cell_name}\"");
+                    continue;
+                };
+                config.jump_grid.insert(key, cell);
+            }
+
+            for (key_name, action_name) in &self.bindings {
+                let (Some(key), Some(action)) =
+                    (key_from_name(key_name), Action::from_name(action_name))
+                else {
+                    println!("Ignoring unknown [bindings] entry: {key_name} = \"{action_name}\"");
+                    continue;
+                };
+                config.bindings.insert(key, action);
+            }
+
+            if let Some(slow) = self.speed.slow {
+                config.slow_speed = slow;
+            }
+            if let Some(ultra_fast) = self.speed.ultra_fast {
+                config.ultra_fast_speed = ultra_fast;
+            }
+            if let Some(initial_velocity) = self.scroll.initial_velocity {
+                config.scroll_initial_velocity = initial_velocity;
+            }
+            if let Some(deceleration) = self.scroll.deceleration {
+                config.scroll_deceleration = deceleration;
+            }
+            if let Some(min_velocity) = self.scroll.min_velocity {
+                config.scroll_min_velocity = min_velocity;
+            }
+            if let Some(frame_delay_ms) = self.scroll.frame_delay_ms {
+                config.scroll_frame_delay_ms = frame_delay_ms;
+            }
+
+            config
+        }
+    }
+
+    /// Direction vectors for the `move_*` action names `[movement]` uses.
+    fn direction_from_name(name: &str) -> Option<(f64, f64)> {
+        Some(match name {
+            "move_left" => (-1., 0.),
+            "move_right" => (1., 0.),
+            "move_down" => (0., 1.),
+            "move_up" => (0., -1.),
+            "move_up_left" => (-1., -1.),
+            "move_up_right" => (1., -1.),
+            "move_down_left" => (-1., 1.),
+            "move_down_right" => (1., 1.),
+            _ => return None,
+        })
+    }
+
+    /// `"col,row"` (0-indexed, 4 columns x 3 rows) for `[jump_grid]` entries.
+    fn cell_from_name(name: &str) -> Option<(f64, f64)> {
+        let (col, row) = name.split_once(',')?;
+        let col: f64 = col.trim().parse().ok()?;
+        let row: f64 = row.trim().parse().ok()?;
+        if !(0.0..4.0).contains(&col) || !(0.0..3.0).contains(&row) {
+            return None;
+        }
+        Some((col, row))
+    }
+
+    /// Maps a config key name onto its `rdev::Key`. Letters are their own
+    /// name (`"h"`, `"q"`, ...); everything else spells out the `rdev::Key`
+    /// variant in snake_case (`"shift_left"`, `"control_right"`, `"caps_lock"`).
+    fn key_from_name(name: &str) -> Option<Key> {
+        if name.len() == 1 {
+            if let Some(letter) = name.chars().next().filter(|c| c.is_ascii_alphabetic()) {
+                return letter_key(letter.to_ascii_lowercase());
+            }
+        }
+        Some(match name {
+            "space" => Key::Space,
+            "escape" | "esc" => Key::Escape,
+            "shift_left" => Key::ShiftLeft,
+            "shift_right" => Key::ShiftRight,
+            "control_left" | "ctrl_left" => Key::ControlLeft,
+            "control_right" | "ctrl_right" => Key::ControlRight,
+            "caps_lock" => Key::CapsLock,
+            "alt" => Key::Alt,
+            _ => return None,
+        })
+    }
+
+    fn letter_key(letter: char) -> Option<Key> {
+        Some(match letter {
+            'a' => Key::KeyA,
+            'b' => Key::KeyB,
+            'c' => Key::KeyC,
+            'd' => Key::KeyD,
+            'e' => Key::KeyE,
+            'f' => Key::KeyF,
+            'g' => Key::KeyG,
+            'h' => Key::KeyH,
+            'i' => Key::KeyI,
+            'j' => Key::KeyJ,
+            'k' => Key::KeyK,
+            'l' => Key::KeyL,
+            'm' => Key::KeyM,
+            'n' => Key::KeyN,
+            'o' => Key::KeyO,
+            'p' => Key::KeyP,
+            'q' => Key::KeyQ,
+            'r' => Key::KeyR,
+            's' => Key::KeyS,
+            't' => Key::KeyT,
+            'u' => Key::KeyU,
+            'v' => Key::KeyV,
+            'w' => Key::KeyW,
+            'x' => Key::KeyX,
+            'y' => Key::KeyY,
+            'z' => Key::KeyZ,
+            _ => return None,
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn key_from_name_accepts_single_letters() {
+            assert_eq!(key_from_name("h"), Some(Key::KeyH));
+            assert_eq!(key_from_name("m"), Some(Key::KeyM));
+        }
+
+        #[test]
+        fn key_from_name_accepts_named_keys() {
+            assert_eq!(key_from_name("shift_left"), Some(Key::ShiftLeft));
+            assert_eq!(key_from_name("ctrl_right"), Some(Key::ControlRight));
+            assert_eq!(key_from_name("caps_lock"), Some(Key::CapsLock));
+        }
+
+        #[test]
+        fn key_from_name_rejects_unknown_names() {
+            assert_eq!(key_from_name("banana"), None);
+            assert_eq!(key_from_name(""), None);
+        }
+
+        #[test]
+        fn direction_from_name_covers_all_eight_directions() {
+            assert_eq!(direction_from_name("move_left"), Some((-1., 0.)));
+            assert_eq!(direction_from_name("move_right"), Some((1., 0.)));
+            assert_eq!(direction_from_name("move_down"), Some((0., 1.)));
+            assert_eq!(direction_from_name("move_up"), Some((0., -1.)));
+            assert_eq!(direction_from_name("move_up_left"), Some((-1., -1.)));
+            assert_eq!(direction_from_name("move_up_right"), Some((1., -1.)));
+            assert_eq!(direction_from_name("move_down_left"), Some((-1., 1.)));
+            assert_eq!(direction_from_name("move_down_right"), Some((1., 1.)));
+            assert_eq!(direction_from_name("sideways"), None);
+        }
+
+        #[test]
+        fn cell_from_name_parses_in_range_cells() {
+            assert_eq!(cell_from_name("0,0"), Some((0., 0.)));
+            assert_eq!(cell_from_name(" 3 , 2 "), Some((3., 2.)));
+        }
+
+        #[test]
+        fn cell_from_name_rejects_out_of_range_cells() {
+            assert_eq!(cell_from_name("10,10"), None);
+            assert_eq!(cell_from_name("4,0"), None);
+            assert_eq!(cell_from_name("0,3"), None);
+            assert_eq!(cell_from_name("-1,0"), None);
+        }
+
+        #[test]
+        fn cell_from_name_rejects_malformed_input() {
+            assert_eq!(cell_from_name("not-a-cell"), None);
+            assert_eq!(cell_from_name("1"), None);
+        }
+
+        #[test]
+        fn action_from_name_maps_known_actions() {
+            assert_eq!(Action::from_name("left_click"), Some(Action::LeftClick));
+            assert_eq!(Action::from_name("exit"), Some(Action::Exit));
+            assert_eq!(Action::from_name("nonsense"), None);
+        }
+    }
+}
+
+lazy_static! {
+    /// Loaded once at startup; see the `config` module for the file format.
+    static ref CONFIG: config::Config = config::Config::load();
+}
 
 static mut SCREEN_WIDTH: f64 = 0.;
 static mut SCREEN_HEIGHT: f64 = 0.;
 
+/// The modal state the input dispatcher is currently in. `Normal` is plain
+/// vim-style cursor movement; the others borrow it for a different purpose
+/// (scrolling, hint-label entry, a held-down drag).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Normal,
+    Scroll,
+    Hint,
+    Drag,
+}
+
+/// All mutable state `callback` used to thread through `static mut` globals,
+/// now held behind a single lock so none of it needs `unsafe`. The screen
+/// size is the exception: it is still read from the `SCREEN_WIDTH` and
+/// `SCREEN_HEIGHT` statics, which `main` sets before `grab` starts - mirroring the modifier-set + mode-enum design terminal
+/// input handlers (e.g. Alacritty) use for the same kind of dispatch.
+struct InputState {
+    mode: Mode,
+    held_modifiers: HashSet<Key>,
+    position: (f64, f64),
+    /// Direction of whichever movement key is currently held, if any.
+    move_direction: Option<(f64, f64)>,
+    /// The speed the active move animation last reached; the release decay
+    /// started from it keeps its own copy and does not write it back.
+    move_velocity: f64,
+    /// Bumped on every press, release, or mode change so a stale animation
+    /// thread notices it's been superseded and exits on its next tick.
+    move_generation: u64,
+}
+
+impl InputState {
+    /// The (initial, ceiling) velocity a fresh move animation should ramp
+    /// between, given which speed-modifier keys (as bound in
+    /// `CONFIG.bindings`) are currently held: a `SlowSpeed` modifier pins
+    /// the crawl at `CONFIG.slow_speed`, an `UltraFastSpeed` modifier skips
+    /// straight to `CONFIG.ultra_fast_speed`, and with neither held it
+    /// ramps from one to the other.
+    fn move_ramp_bounds(&self) -> (f64, f64) {
+        let mut slow = false;
+        let mut ultra_fast = false;
+        for key in &self.held_modifiers {
+            match CONFIG.bindings.get(key) {
+                Some(config::Action::SlowSpeed) => slow = true,
+                Some(config::Action::UltraFastSpeed) => ultra_fast = true,
+                _ => {}
+            }
+        }
+
+        if slow {
+            (CONFIG.slow_speed, CONFIG.slow_speed)
+        } else if ultra_fast {
+            (CONFIG.ultra_fast_speed, CONFIG.ultra_fast_speed)
+        } else {
+            (CONFIG.slow_speed, CONFIG.ultra_fast_speed)
+        }
+    }
+}
+
+lazy_static! {
+    static ref INPUT_STATE: Mutex<InputState> = Mutex::new(InputState {
+        mode: Mode::Normal,
+        held_modifiers: HashSet::new(),
+        position: (0., 0.),
+        move_direction: None,
+        move_velocity: CONFIG.slow_speed,
+        move_generation: 0,
+    });
+}
+
 #[derive(Debug, Clone)]
 pub struct ClickableElement {
     pub text: String,
@@ -34,6 +476,41 @@ pub struct ClickableElement {
     pub role: String,
 }
 
+/// Shared clickability vocabulary every backend filters against, named
+/// after the macOS accessibility API's AX role constants since that's the
+/// backend this project started with. Windows UI Automation and Linux
+/// AT-SPI backends translate their own native role names into these before
+/// calling this.
+fn is_clickable_role(role: &str) -> bool {
+    matches!(
+        role,
+        "AXButton"
+            | "AXMenuButton"
+            | "AXPopUpButton"
+            | "AXCheckBox"
+            | "AXRadioButton"
+            | "AXTextField"
+            | "AXTextArea"
+            | "AXSearchField"
+            | "AXLink"
+            | "AXMenuItem"
+            | "AXTab"
+            | "AXSlider"
+            | "AXIncrementor"
+            | "AXDecrementor"
+            | "AXComboBox"
+            | "AXDisclosureTriangle"
+            | "AXStepper"
+            | "AXSegmentedControl"
+            | "AXTabGroup"
+            | "AXScrollBar"
+            | "AXTable"
+            | "AXOutline"
+            | "AXList"
+            | "AXImage"
+    )
+}
+
 #[cfg(target_os = "macos")]
 mod clickable_detector {
     use super::*;
@@ -161,18 +638,11 @@ mod clickable_detector {
     unsafe fn is_clickable_element(element: AXUIElementRef) -> bool {
         // Get the role of the element
         if let Some(role) = get_element_role(element) {
-            match role.as_str() {
-                "AXButton" | "AXMenuButton" | "AXPopUpButton" | "AXCheckBox" | 
-                "AXRadioButton" | "AXTextField" | "AXTextArea" | "AXSearchField" |
-                "AXLink" | "AXMenuItem" | "AXTab" | "AXSlider" | "AXIncrementor" |
-                "AXDecrementor" | "AXComboBox" | "AXDisclosureTriangle" |
-                "AXStepper" | "AXSegmentedControl" | "AXTabGroup" | "AXScrollBar" |
-                "AXTable" | "AXOutline" | "AXList" | "AXImage" => {
-                    // Additional check: element should be enabled and visible
-                    is_element_enabled(element) && is_element_visible(element)
-                },
-                _ => false
+            if !super::is_clickable_role(&role) {
+                return false;
             }
+            // Additional check: element should be enabled and visible
+            is_element_enabled(element) && is_element_visible(element)
         } else {
             false
         }
@@ -327,17 +797,587 @@ mod clickable_detector {
     }
 }
 
-#[cfg(not(target_os = "macos"))]
+#[cfg(target_os = "windows")]
 mod clickable_detector {
     use super::*;
-    
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+    };
+    use windows::Win32::UI::Accessibility::{
+        CUIAutomation, IUIAutomation, IUIAutomationElement, TreeScope_Descendants,
+    };
+    use windows::core::Result;
+
+    pub fn find_clickable_elements() -> Vec<ClickableElement> {
+        match walk_desktop() {
+            Ok(elements) => elements,
+            Err(err) => {
+                println!("Failed to walk the UI Automation tree: {err:?}");
+                Vec::new()
+            }
+        }
+    }
+
+    fn walk_desktop() -> Result<Vec<ClickableElement>> {
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+            // `CUIAutomation` is the CLSID constant for the UI Automation
+            // COM server, not a type - it's instantiated through
+            // `CoCreateInstance`, the same as any other COM coclass.
+            let automation: IUIAutomation =
+                CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER)?;
+            let root = automation.GetRootElement()?;
+            let condition = automation.CreateTrueCondition()?;
+            let found = root.FindAll(TreeScope_Descendants, &condition)?;
+
+            let mut elements = Vec::new();
+            for i in 0..found.Length()? {
+                let element = found.GetElement(i)?;
+                if let Some(clickable) = to_clickable_element(&element) {
+                    elements.push(clickable);
+                }
+            }
+            Ok(elements)
+        }
+    }
+
+    fn to_clickable_element(element: &IUIAutomationElement) -> Option<ClickableElement> {
+        unsafe {
+            let control_type = element.CurrentControlType().ok()?.0;
+            let role = control_type_to_ax_role(control_type)?;
+            if !super::is_clickable_role(role) {
+                return None;
+            }
+
+            let rect = element.CurrentBoundingRectangle().ok()?;
+            let name = element
+                .CurrentName()
+                .map(|name| name.to_string())
+                .unwrap_or_default();
+
+            Some(ClickableElement {
+                text: if name.is_empty() {
+                    format!("{role} Element")
+                } else {
+                    name
+                },
+                x: rect.left as f64,
+                y: rect.top as f64,
+                width: (rect.right - rect.left) as f64,
+                height: (rect.bottom - rect.top) as f64,
+                role: role.to_string(),
+            })
+        }
+    }
+
+    /// Maps a `UIA_*ControlTypeId` onto the shared AX-style role vocabulary.
+    /// These IDs are a newtype wrapping `u32` (`UIA_CONTROLTYPE_ID` in
+    /// `windows::Win32::UI::Accessibility`), matching what
+    /// `CurrentControlType` returns, so both sides of the comparison go
+    /// through `.0`.
+    fn control_type_to_ax_role(control_type: u32) -> Option<&'static str> {
+        use windows::Win32::UI::Accessibility::*;
+        Some(match control_type {
+            t if t == UIA_ButtonControlTypeId.0 => "AXButton",
+            t if t == UIA_CheckBoxControlTypeId.0 => "AXCheckBox",
+            t if t == UIA_RadioButtonControlTypeId.0 => "AXRadioButton",
+            t if t == UIA_EditControlTypeId.0 => "AXTextField",
+            t if t == UIA_HyperlinkControlTypeId.0 => "AXLink",
+            t if t == UIA_MenuItemControlTypeId.0 => "AXMenuItem",
+            t if t == UIA_TabItemControlTypeId.0 => "AXTab",
+            t if t == UIA_SliderControlTypeId.0 => "AXSlider",
+            t if t == UIA_ComboBoxControlTypeId.0 => "AXComboBox",
+            t if t == UIA_ScrollBarControlTypeId.0 => "AXScrollBar",
+            t if t == UIA_TableControlTypeId.0 => "AXTable",
+            t if t == UIA_ListControlTypeId.0 => "AXList",
+            t if t == UIA_ImageControlTypeId.0 => "AXImage",
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod clickable_detector {
+    use super::*;
+    use atspi::{
+        connection::AccessibilityConnection,
+        proxy::{accessible::AccessibleProxy, component::ComponentProxy},
+    };
+
+    // The accessible tree is reached through the central AT-SPI registry
+    // daemon at this well-known bus name and object path - part of the
+    // AT-SPI2 D-Bus wire protocol, not something a client discovers
+    // dynamically.
+    const REGISTRY_DESTINATION: &str = "org.a11y.atspi.Registry";
+    const REGISTRY_ROOT_PATH: &str = "/org/a11y/atspi/accessible/root";
+
+    pub fn find_clickable_elements() -> Vec<ClickableElement> {
+        let runtime = match tokio::runtime::Runtime::new() {
+            Ok(runtime) => runtime,
+            Err(err) => {
+                println!("Failed to start AT-SPI runtime: {err:?}");
+                return Vec::new();
+            }
+        };
+        match runtime.block_on(walk_desktop()) {
+            Ok(elements) => elements,
+            Err(err) => {
+                println!("Failed to query AT-SPI: {err:?}");
+                Vec::new()
+            }
+        }
+    }
+
+    // `atspi`'s client surface is async-first; this crate is otherwise
+    // synchronous, so the walk above spins up a short-lived tokio runtime
+    // rather than threading an executor through the rest of main.rs.
+    async fn walk_desktop() -> zbus::Result<Vec<ClickableElement>> {
+        let connection = AccessibilityConnection::open().await?;
+        let root = AccessibleProxy::builder(connection.connection())
+            .destination(REGISTRY_DESTINATION)?
+            .path(REGISTRY_ROOT_PATH)?
+            .build()
+            .await?;
+
+        let mut elements = Vec::new();
+        collect_accessible(connection.connection(), root, &mut elements, 0).await?;
+        Ok(elements)
+    }
+
+    fn collect_accessible<'a>(
+        connection: &'a zbus::Connection,
+        accessible: AccessibleProxy<'a>,
+        elements: &'a mut Vec<ClickableElement>,
+        depth: u32,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = zbus::Result<()>> + 'a>> {
+        Box::pin(async move {
+            if depth > 10 {
+                return Ok(());
+            }
+
+            let role_name = accessible.get_role_name().await?;
+            if let Some(role) = role_to_ax_role(&role_name) {
+                if super::is_clickable_role(role) {
+                    // `get_children` hands back plain name/path pairs, not
+                    // ready-made proxies, so the Component-interface proxy
+                    // for this same object has to be rebuilt from the
+                    // accessible's own destination/path rather than
+                    // converted from it.
+                    let component = ComponentProxy::builder(connection)
+                        .destination(accessible.destination().to_owned())?
+                        .path(accessible.path().to_owned())?
+                        .build()
+                        .await;
+                    if let Ok(component) = component {
+                        let name = accessible.name().await.unwrap_or_default();
+                        let (x, y, width, height) =
+                            component.get_extents(atspi::CoordType::Screen).await?;
+                        elements.push(ClickableElement {
+                            text: if name.is_empty() {
+                                format!("{role} Element")
+                            } else {
+                                name
+                            },
+                            x: x as f64,
+                            y: y as f64,
+                            width: width as f64,
+                            height: height as f64,
+                            role: role.to_string(),
+                        });
+                    }
+                }
+            }
+
+            for child in accessible.get_children().await? {
+                let child_accessible = AccessibleProxy::builder(connection)
+                    .destination(child.name)?
+                    .path(child.path)?
+                    .build()
+                    .await?;
+                collect_accessible(connection, child_accessible, elements, depth + 1).await?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Maps an AT-SPI role name (as returned by `GetRoleName`, e.g. "push
+    /// button") onto the shared AX-style role vocabulary.
+    fn role_to_ax_role(role_name: &str) -> Option<&'static str> {
+        Some(match role_name {
+            "push button" => "AXButton",
+            "toggle button" => "AXMenuButton",
+            "check box" => "AXCheckBox",
+            "radio button" => "AXRadioButton",
+            "entry" => "AXTextField",
+            "link" => "AXLink",
+            "menu item" => "AXMenuItem",
+            "page tab" => "AXTab",
+            "slider" => "AXSlider",
+            "combo box" => "AXComboBox",
+            "scroll bar" => "AXScrollBar",
+            "table" => "AXTable",
+            "list" => "AXList",
+            "image" => "AXImage",
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+mod clickable_detector {
+    use super::*;
+
     pub fn find_clickable_elements() -> Vec<ClickableElement> {
-        println!("⚠️  Clickable element detection is only supported on macOS currently");
-        println!("    The accessibility APIs required for this feature are platform-specific.");
+        println!("⚠️  Clickable element detection is not supported on this platform yet.");
+        println!("    Supported platforms: macOS, Windows, Linux.");
         Vec::new()
     }
 }
 
+// Home-row alphabet used for hint labels. `assign_hint_labels` hands its
+// labels out in sorted order, not shortest-first, so the elements that come
+// first do not necessarily get the single-key labels.
+const HINT_ALPHABET: &str = "asdfghjkl";
+
+/// Assigns a short, prefix-free label to each of `count` elements using the
+/// same breadth-balanced scheme Vimium uses for its link hints: labels are
+/// grown one character at a time across the whole alphabet (BFS, not a flat
+/// `alphabet.len()^L` product), so the tree is only as deep as it needs to be
+/// and no label is ever a prefix of another - once one is typed in full it's
+/// unambiguous.
+fn assign_hint_labels(count: usize) -> Vec<String> {
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let alphabet: Vec<char> = HINT_ALPHABET.chars().collect();
+    let mut hints: Vec<String> = vec![String::new()];
+    let mut offset = 0;
+    while hints.len() - offset < count || hints.len() == 1 {
+        let hint = hints[offset].clone();
+        offset += 1;
+        for &ch in &alphabet {
+            hints.push(format!("{ch}{hint}"));
+        }
+    }
+
+    let mut labels: Vec<String> = hints[offset..offset + count].to_vec();
+    labels.sort();
+    labels.iter().map(|s| s.chars().rev().collect()).collect()
+}
+
+#[cfg(test)]
+mod hint_label_tests {
+    use super::*;
+
+    #[test]
+    fn assign_hint_labels_returns_one_label_per_element() {
+        assert_eq!(assign_hint_labels(0).len(), 0);
+        assert_eq!(assign_hint_labels(1).len(), 1);
+        assert_eq!(assign_hint_labels(9).len(), 9);
+        assert_eq!(assign_hint_labels(20).len(), 20);
+    }
+
+    #[test]
+    fn assign_hint_labels_are_unique_and_prefix_free() {
+        let labels = assign_hint_labels(50);
+        for (i, a) in labels.iter().enumerate() {
+            for (j, b) in labels.iter().enumerate() {
+                if i != j {
+                    assert!(!b.starts_with(a.as_str()), "{a} is a prefix of {b}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn assign_hint_labels_uses_only_the_hint_alphabet() {
+        for label in assign_hint_labels(100) {
+            assert!(label.chars().all(|c| HINT_ALPHABET.contains(c)));
+        }
+    }
+}
+
+// Transparent, click-through, always-on-top overlay that paints hint labels
+// on top of whatever window currently has focus.
+mod hint_overlay {
+    use super::ClickableElement;
+    use winit::{
+        dpi::{LogicalPosition, LogicalSize},
+        event::{Event as WinitEvent, WindowEvent},
+        event_loop::{ControlFlow, EventLoop},
+        platform::run_return::EventLoopExtRunReturn,
+        window::WindowBuilder,
+    };
+
+    /// One labelled hint, paired with the element it will click through to.
+    pub struct Hint {
+        pub label: String,
+        pub element: ClickableElement,
+    }
+
+    /// What a hint session has decided to do, reported once per redraw by
+    /// the `decide` closure passed to `run`.
+    pub enum Decision {
+        /// Keep the overlay up, showing only these hints.
+        Continue(Vec<Hint>),
+        /// Commit to this element and tear the overlay down.
+        Click(ClickableElement),
+        /// The user backed out (Esc, or no hints left); tear down with no click.
+        Cancel,
+    }
+
+    /// Runs its own short-lived `winit` event loop on the calling thread,
+    /// drawing `label @ (x, y)` for every still-visible hint until `decide`
+    /// reports `Click` or `Cancel`. Returns the clicked element, if any, only
+    /// *after* the event loop has exited and the overlay window has been
+    /// dropped - callers must wait for this return before dispatching a
+    /// synthetic click, otherwise the still-on-screen, not-reliably
+    /// click-through overlay could swallow it instead of the real target.
+    pub fn run(
+        width: f64,
+        height: f64,
+        mut decide: impl FnMut() -> Decision,
+    ) -> Option<ClickableElement> {
+        let mut event_loop = EventLoop::new();
+        let window = match WindowBuilder::new()
+            .with_title("vimouse-hints")
+            .with_inner_size(LogicalSize::new(width, height))
+            .with_position(LogicalPosition::new(0.0, 0.0))
+            .with_decorations(false)
+            .with_transparent(true)
+            .with_always_on_top(true)
+            .build(&event_loop)
+        {
+            Ok(window) => window,
+            Err(err) => {
+                println!("Could not open hint overlay window: {err:?}");
+                return None;
+            }
+        };
+        // Let input fall through to whatever is underneath the overlay; the
+        // window exists purely to paint labels.
+        set_click_through(&window);
+
+        let mut clicked = None;
+        event_loop.run_return(|event, _, control_flow| {
+            *control_flow = ControlFlow::Poll;
+            match event {
+                WinitEvent::RedrawRequested(_) => match decide() {
+                    Decision::Continue(hints) => paint(&window, width, height, &hints),
+                    Decision::Click(element) => {
+                        clicked = Some(element);
+                        *control_flow = ControlFlow::Exit;
+                    }
+                    Decision::Cancel => *control_flow = ControlFlow::Exit,
+                },
+                WinitEvent::MainEventsCleared => window.request_redraw(),
+                WinitEvent::WindowEvent {
+                    event: WindowEvent::CloseRequested,
+                    ..
+                } => *control_flow = ControlFlow::Exit,
+                _ => {}
+            }
+        });
+        // Drop the overlay window synchronously before handing `clicked`
+        // back, so a synthetic click is never sent while it's still on screen.
+        drop(window);
+        clicked
+    }
+
+    #[cfg(target_os = "macos")]
+    fn set_click_through(window: &winit::window::Window) {
+        use cocoa::appkit::NSWindow;
+        use cocoa::base::{id, YES};
+        use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+
+        if let RawWindowHandle::AppKit(handle) = window.raw_window_handle() {
+            unsafe {
+                let ns_window = handle.ns_window as id;
+                ns_window.setIgnoresMouseEvents_(YES);
+            }
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn set_click_through(window: &winit::window::Window) {
+        use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+        use windows::Win32::Foundation::HWND;
+        use windows::Win32::UI::WindowsAndMessaging::{
+            GetWindowLongPtrW, SetWindowLongPtrW, GWL_EXSTYLE, WS_EX_LAYERED, WS_EX_TRANSPARENT,
+        };
+
+        if let RawWindowHandle::Win32(handle) = window.raw_window_handle() {
+            unsafe {
+                let hwnd = HWND(handle.hwnd as isize);
+                let style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+                SetWindowLongPtrW(
+                    hwnd,
+                    GWL_EXSTYLE,
+                    style | WS_EX_LAYERED.0 as isize | WS_EX_TRANSPARENT.0 as isize,
+                );
+            }
+        }
+    }
+
+    // No X11/Wayland input-shape implementation yet. Correctness doesn't
+    // depend on it, since `run` only ever hands a clicked element back to
+    // the caller after the overlay window above it has already been
+    // dropped - see `run`'s doc comment.
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    fn set_click_through(_window: &winit::window::Window) {}
+
+    /// Blits each hint's label near its element's top-left corner using a
+    /// minimal bitmap font covering just the home-row letters hints use.
+    fn paint(window: &winit::window::Window, width: f64, height: f64, hints: &[Hint]) {
+        use softbuffer::{Context, Surface};
+
+        let context = match unsafe { Context::new(window) } {
+            Ok(context) => context,
+            Err(_) => return,
+        };
+        let mut surface = match unsafe { Surface::new(&context, window) } {
+            Ok(surface) => surface,
+            Err(_) => return,
+        };
+        let (w, h) = (width as u32, height as u32);
+        if surface.resize(w.try_into().unwrap(), h.try_into().unwrap()).is_err() {
+            return;
+        }
+        let mut buffer = match surface.buffer_mut() {
+            Ok(buffer) => buffer,
+            Err(_) => return,
+        };
+        buffer.fill(0); // fully transparent background
+
+        for hint in hints {
+            draw_label(&mut buffer, w, h, hint.element.x, hint.element.y, &hint.label);
+        }
+        let _ = buffer.present();
+    }
+
+    // 3x5 glyphs for the nine home-row letters hint labels are built from.
+    const GLYPH_WIDTH: usize = 3;
+    const GLYPH_HEIGHT: usize = 5;
+    fn glyph(ch: char) -> [u8; GLYPH_WIDTH * GLYPH_HEIGHT] {
+        #[rustfmt::skip]
+        let rows: [u8; 15] = match ch {
+            'a' => [0,1,0, 1,0,1, 1,1,1, 1,0,1, 1,0,1],
+            's' => [1,1,1, 1,0,0, 1,1,1, 0,0,1, 1,1,1],
+            'd' => [1,1,0, 1,0,1, 1,0,1, 1,0,1, 1,1,0],
+            'f' => [1,1,1, 1,0,0, 1,1,0, 1,0,0, 1,0,0],
+            'g' => [0,1,1, 1,0,0, 1,0,1, 1,0,1, 0,1,1],
+            'h' => [1,0,1, 1,0,1, 1,1,1, 1,0,1, 1,0,1],
+            'j' => [0,0,1, 0,0,1, 0,0,1, 1,0,1, 0,1,0],
+            'k' => [1,0,1, 1,1,0, 1,0,0, 1,1,0, 1,0,1],
+            'l' => [1,0,0, 1,0,0, 1,0,0, 1,0,0, 1,1,1],
+            _ => [0; 15],
+        };
+        rows
+    }
+
+    fn draw_label(buffer: &mut [u32], width: u32, height: u32, x: f64, y: f64, label: &str) {
+        let pixel = 0xFFFFFF00u32; // opaque yellow, vimium-style hint pill
+        for (i, ch) in label.to_ascii_lowercase().chars().enumerate() {
+            let glyph_x = x as i64 + (i * (GLYPH_WIDTH + 1)) as i64;
+            let glyph = glyph(ch);
+            for row in 0..GLYPH_HEIGHT {
+                for col in 0..GLYPH_WIDTH {
+                    if glyph[row * GLYPH_WIDTH + col] == 0 {
+                        continue;
+                    }
+                    let px = glyph_x + col as i64;
+                    let py = y as i64 + row as i64;
+                    if px < 0 || py < 0 || px as u32 >= width || py as u32 >= height {
+                        continue;
+                    }
+                    buffer[py as usize * width as usize + px as usize] = pixel;
+                }
+            }
+        }
+    }
+}
+
+/// Maps a physical key to the hint-alphabet character it represents, if any.
+fn hint_char_for_key(key: Key) -> Option<char> {
+    let ch = match key {
+        Key::KeyA => 'a',
+        Key::KeyS => 's',
+        Key::KeyD => 'd',
+        Key::KeyF => 'f',
+        Key::KeyG => 'g',
+        Key::KeyH => 'h',
+        Key::KeyJ => 'j',
+        Key::KeyK => 'k',
+        Key::KeyL => 'l',
+        _ => return None,
+    };
+    Some(ch)
+}
+
+/// Enters hint mode: finds every clickable element currently on screen,
+/// labels them, draws the overlay, and warps the cursor onto whichever
+/// element's label the user types out in full. Esc cancels.
+fn enter_hint_mode() {
+    let elements = clickable_detector::find_clickable_elements();
+    if elements.is_empty() {
+        println!("No clickable elements to hint.");
+        return;
+    }
+
+    let labels = assign_hint_labels(elements.len());
+    let hints: Vec<hint_overlay::Hint> = labels
+        .into_iter()
+        .zip(elements)
+        .map(|(label, element)| hint_overlay::Hint { label, element })
+        .collect();
+
+    *HINT_PREFIX.lock().unwrap() = String::new();
+    INPUT_STATE.lock().unwrap().mode = Mode::Hint;
+    let (screen_width, screen_height) = unsafe { (SCREEN_WIDTH, SCREEN_HEIGHT) };
+
+    let clicked = hint_overlay::run(screen_width, screen_height, || {
+        if INPUT_STATE.lock().unwrap().mode != Mode::Hint {
+            return hint_overlay::Decision::Cancel;
+        }
+        let typed = HINT_PREFIX.lock().unwrap().clone();
+        let visible: Vec<hint_overlay::Hint> = hints
+            .iter()
+            .filter(|hint| hint.label.starts_with(typed.as_str()))
+            .map(|hint| hint_overlay::Hint {
+                label: hint.label.clone(),
+                element: hint.element.clone(),
+            })
+            .collect();
+
+        if visible.len() == 1 && visible[0].label == typed {
+            return hint_overlay::Decision::Click(visible[0].element.clone());
+        }
+        if visible.is_empty() {
+            return hint_overlay::Decision::Cancel;
+        }
+        hint_overlay::Decision::Continue(visible)
+    });
+
+    // The overlay window is guaranteed gone by the time `run` returns, so
+    // it's safe to click now without the invisible-but-still-present
+    // overlay intercepting the synthetic event.
+    INPUT_STATE.lock().unwrap().mode = Mode::Normal;
+    if let Some(element) = clicked {
+        click_element(&element);
+    }
+}
+
+fn click_element(element: &ClickableElement) {
+    let (x, y) = (
+        element.x + element.width / 2.,
+        element.y + element.height / 2.,
+    );
+    send(&EventType::MouseMove { x, y });
+    send(&EventType::ButtonPress(Button::Left));
+    send(&EventType::ButtonRelease(Button::Left));
+}
+
 fn print_clickable_elements() {
     println!("🔍 Searching for clickable elements on screen...");
     let start_time = std::time::Instant::now();
@@ -417,30 +1457,15 @@ fn print_clickable_elements() {
 }
 
 lazy_static! {
-    static ref MOVEMENT_MAP: HashMap<Key, (f64, f64)> = HashMap::from([
-        (Key::KeyH, (-1., 0.)),
-        (Key::KeyL, (1., 0.)),
-        (Key::KeyJ, (0., 1.)),
-        (Key::KeyK, (0., -1.)),
-        (Key::KeyY, (-1., -1.)),
-        (Key::KeyU, (1., -1.)),
-        (Key::KeyB, (-1., 1.)),
-        (Key::KeyN, (1., 1.)),
-    ]);
-    static ref SCREEN_CELL_MAP: HashMap<Key, (f64, f64)> = HashMap::from([
-        (Key::KeyQ, (0., 0.)),
-        (Key::KeyW, (1., 0.)),
-        (Key::KeyE, (2., 0.)),
-        (Key::KeyR, (3., 0.)),
-        (Key::KeyA, (0., 1.)),
-        (Key::KeyS, (1., 1.)),
-        (Key::KeyD, (2., 1.)),
-        (Key::KeyF, (3., 1.)),
-        (Key::KeyZ, (0., 2.)),
-        (Key::KeyX, (1., 2.)),
-        (Key::KeyC, (2., 2.)),
-        (Key::KeyV, (3., 2.)),
-    ]);
+    // Prefix of the label the user has typed so far while in hint mode.
+    static ref HINT_PREFIX: Mutex<String> = Mutex::new(String::new());
+    // Set once by `main()` before `grab()` starts. `winit` requires its
+    // `EventLoop` to be created on the thread the OS considers "main" (this
+    // is enforced on macOS), but `grab()` also wants to own whichever
+    // thread calls it, so the two can't share one. The grab callback sends
+    // a request here instead of spawning the hint overlay itself, and
+    // `main()` hosts the actual overlay loop.
+    static ref HINT_REQUEST_TX: Mutex<Option<mpsc::Sender<()>>> = Mutex::new(None);
 }
 
 #[cfg(target_os = "macos")]
@@ -474,11 +1499,13 @@ fn send(event_type: &EventType) {
 fn send_smooth_scroll(direction_x: f64, direction_y: f64) {
     // Spawn a thread to handle the smooth scroll animation
     thread::spawn(move || {
-        let mut velocity_x = direction_x * SCROLL_INITIAL_VELOCITY;
-        let mut velocity_y = direction_y * SCROLL_INITIAL_VELOCITY;
+        let mut velocity_x = direction_x * CONFIG.scroll_initial_velocity;
+        let mut velocity_y = direction_y * CONFIG.scroll_initial_velocity;
 
         // Continue scrolling until velocity drops below minimum
-        while velocity_x.abs() > SCROLL_MIN_VELOCITY || velocity_y.abs() > SCROLL_MIN_VELOCITY {
+        while velocity_x.abs() > CONFIG.scroll_min_velocity
+            || velocity_y.abs() > CONFIG.scroll_min_velocity
+        {
             // Send scroll event with current velocity
             let delta_x = velocity_x as i64;
             let delta_y = velocity_y as i64;
@@ -488,190 +1515,329 @@ fn send_smooth_scroll(direction_x: f64, direction_y: f64) {
             }
 
             // Apply deceleration
-            velocity_x *= SCROLL_DECELERATION;
-            velocity_y *= SCROLL_DECELERATION;
+            velocity_x *= CONFIG.scroll_deceleration;
+            velocity_y *= CONFIG.scroll_deceleration;
 
             // Stop if velocity is too small
-            if velocity_x.abs() < SCROLL_MIN_VELOCITY {
+            if velocity_x.abs() < CONFIG.scroll_min_velocity {
                 velocity_x = 0.0;
             }
-            if velocity_y.abs() < SCROLL_MIN_VELOCITY {
+            if velocity_y.abs() < CONFIG.scroll_min_velocity {
                 velocity_y = 0.0;
             }
 
             // Wait for next frame
-            thread::sleep(time::Duration::from_millis(SCROLL_FRAME_DELAY_MS));
+            thread::sleep(time::Duration::from_millis(CONFIG.scroll_frame_delay_ms));
         }
     });
 }
 
+fn clamp_to_screen(x: f64, y: f64) -> (f64, f64) {
+    let (width, height) = unsafe { (SCREEN_WIDTH, SCREEN_HEIGHT) };
+    (x.clamp(0.0, width), y.clamp(0.0, height))
+}
+
+/// Starts (or restarts) the held-direction ramp for `direction`. The speed
+/// modifiers in `CONFIG.bindings` still behave as before - a `SlowSpeed`
+/// key pins the cursor at `CONFIG.slow_speed`, an `UltraFastSpeed` key at
+/// `CONFIG.ultra_fast_speed` - but with neither held the cursor glides from
+/// one to the other the longer the key stays down, instead of jumping a
+/// fixed step per keypress.
+///
+/// A no-op if `direction` is already in progress: the OS delivers repeated
+/// `KeyPress` events for a held key, and restarting the ramp on every one
+/// of those would keep the velocity pinned near `initial` instead of
+/// actually ramping up to `ceiling`.
+fn begin_move(direction: (f64, f64)) {
+    let (generation, initial, ceiling) = {
+        let mut state = INPUT_STATE.lock().unwrap();
+        if state.move_direction == Some(direction) {
+            return;
+        }
+        state.move_generation += 1;
+        state.move_direction = Some(direction);
+        let (initial, ceiling) = state.move_ramp_bounds();
+        state.move_velocity = initial;
+        (state.move_generation, initial, ceiling)
+    };
+
+    thread::spawn(move || {
+        let start = time::Instant::now();
+        loop {
+            let (x, y) = {
+                let mut state = INPUT_STATE.lock().unwrap();
+                if state.move_generation != generation {
+                    return;
+                }
+                let progress = (start.elapsed().as_millis() as f64 / MOVE_RAMP_MS).min(1.0);
+                let velocity = initial + (ceiling - initial) * progress;
+                state.move_velocity = velocity;
+                let (x, y) = clamp_to_screen(
+                    state.position.0 + direction.0 * velocity,
+                    state.position.1 + direction.1 * velocity,
+                );
+                state.position = (x, y);
+                (x, y)
+            };
+            send(&EventType::MouseMove { x, y });
+            thread::sleep(time::Duration::from_millis(CONFIG.scroll_frame_delay_ms));
+        }
+    });
+}
+
+/// Releases the held direction, letting the cursor decelerate to a stop
+/// instead of halting instantly - the same momentum curve `send_smooth_scroll`
+/// already uses.
+fn end_move() {
+    let snapshot = {
+        let mut state = INPUT_STATE.lock().unwrap();
+        state.move_generation += 1;
+        state
+            .move_direction
+            .take()
+            .map(|direction| (direction, state.move_velocity, state.move_generation))
+    };
+    let Some((direction, mut velocity, generation)) = snapshot else {
+        return;
+    };
+
+    thread::spawn(move || loop {
+        velocity *= CONFIG.scroll_deceleration;
+        if velocity.abs() < CONFIG.scroll_min_velocity {
+            return;
+        }
+        let (x, y) = {
+            let mut state = INPUT_STATE.lock().unwrap();
+            if state.move_generation != generation {
+                return;
+            }
+            let (x, y) = clamp_to_screen(
+                state.position.0 + direction.0 * velocity,
+                state.position.1 + direction.1 * velocity,
+            );
+            state.position = (x, y);
+            (x, y)
+        };
+        send(&EventType::MouseMove { x, y });
+        thread::sleep(time::Duration::from_millis(CONFIG.scroll_frame_delay_ms));
+    });
+}
+
+/// Cancels any in-flight movement animation without easing to a stop -
+/// used when a mode change makes the current glide meaningless to finish.
+/// A press of a different direction needs no call here: `begin_move`
+/// supersedes the old animation itself.
+fn cancel_move() {
+    let mut state = INPUT_STATE.lock().unwrap();
+    state.move_generation += 1;
+    state.move_direction = None;
+}
+
 fn callback(event: Event) -> Option<Event> {
-    unsafe {
-        return match event.event_type {
-            EventType::MouseMove { x, y } => {
-                MOUSE_POSITION = (x, y);
-                return Some(event);
-            }
-            EventType::KeyPress(key) => {
-                return match key {
-                    /* Movement directions:
-                     *
-                     *  y  k  u
-                     *   ↖ ↑ ↗
-                     * h ← . → l
-                     *   ↙ ↓ ↘
-                     *  b  j  n
-                     *
-                     */
-                    Key::KeyH
-                    | Key::KeyL
-                    | Key::KeyK
-                    | Key::KeyJ
-                    | Key::KeyY
-                    | Key::KeyU
-                    | Key::KeyB
-                    | Key::KeyN => {
-                        if G_KEY_HELD {
-                            // Scroll mode: only handle h, l, j, k for scrolling
-                            match key {
-                                Key::KeyH => {
-                                    // Scroll left with smooth momentum
-                                    send_smooth_scroll(-1.0, 0.0);
-                                    return None;
-                                }
-                                Key::KeyL => {
-                                    // Scroll right with smooth momentum
-                                    send_smooth_scroll(1.0, 0.0);
-                                    return None;
-                                }
-                                Key::KeyJ => {
-                                    // Scroll down with smooth momentum
-                                    send_smooth_scroll(0.0, -1.0);
-                                    return None;
-                                }
-                                Key::KeyK => {
-                                    // Scroll up with smooth momentum
-                                    send_smooth_scroll(0.0, 1.0);
-                                    return None;
-                                }
-                                _ => {
-                                    // Other movement keys are ignored in scroll mode
-                                    return None;
-                                }
-                            }
-                        } else {
-                            // Normal movement mode
-                            if let Some(direction) = MOVEMENT_MAP.get(&key) {
-                                send(&EventType::MouseMove {
-                                    x: MOUSE_POSITION.0 + direction.0 * MOUSE_SPEED,
-                                    y: MOUSE_POSITION.1 + direction.1 * MOUSE_SPEED,
-                                });
-                                return None;
-                            }
-                        }
-                        return Some(event);
-                    }
-                    /* Mouse clicks:
-                     * - Space: Left click
-                     * - Ctrl: Right click
-                     */
-                    Key::Space => {
-                        send(&EventType::ButtonPress(Button::Left));
-                        return None;
-                    }
-                    Key::ControlLeft | Key::ControlRight | Key::CapsLock => {
-                        send(&EventType::ButtonPress(Button::Right));
-                        return None;
-                    }
-                    /* Quick jump to a specific
-                     * area on the screen:
-                     *  ┌─────┬─────┬─────┬─────┐
-                     *  │  Q  │  W  │  E  │  R  │
-                     *  ├─────┼─────┼─────┼─────┤
-                     *  │  A  │  S  │  D  │  F  │
-                     *  ├─────┼─────┼─────┼─────┤
-                     *  │  Z  │  X  │  C  │  V  │
-                     *  └─────┴─────┴─────┴─────┘
-                     */
-                    Key::KeyQ
-                    | Key::KeyW
-                    | Key::KeyE
-                    | Key::KeyR
-                    | Key::KeyA
-                    | Key::KeyS
-                    | Key::KeyD
-                    | Key::KeyF
-                    | Key::KeyZ
-                    | Key::KeyX
-                    | Key::KeyC
-                    | Key::KeyV => {
-                        if let Some((col, row)) = SCREEN_CELL_MAP.get(&key) {
-                            let (x, y) = (
-                                col * SCREEN_WIDTH / 4. + SCREEN_WIDTH / 8.,
-                                row * SCREEN_HEIGHT / 3. + SCREEN_HEIGHT / 6.,
-                            );
-                            send(&EventType::MouseMove { x, y });
-                            return None;
-                        }
-                        return Some(event);
-                    }
-                    /* Others:
-                     * - Esc: Exit
-                     * - Shift: Slow speed
-                     * - Alt: Fast speed
-                     */
-                    Key::Escape => {
-                        std::process::exit(0);
-                    }
-                    Key::ShiftLeft | Key::ShiftRight => {
-                        MOUSE_SPEED = SLOW_SPEED;
-                        return Some(event);
-                    }
-                    Key::Alt => {
-                        MOUSE_SPEED = ULTRA_FAST_SPEED;
-                        return Some(event);
-                    }
-                    Key::KeyG => {
-                        G_KEY_HELD = true;
-                        return None;
-                    }
-                    Key::KeyT => {
-                        G_KEY_HELD = !G_KEY_HELD;
-                        return None;
-                    }
-                    Key::KeyI => {
-                        // Print clickable elements to console
-                        thread::spawn(|| {
-                            print_clickable_elements();
-                        });
-                        return None;
-                    }
-                    _ => Some(event),
+    match event.event_type {
+        EventType::MouseMove { x, y } => {
+            INPUT_STATE.lock().unwrap().position = (x, y);
+            Some(event)
+        }
+        EventType::KeyPress(key) => dispatch_key_press(key, &event),
+        EventType::KeyRelease(key) => dispatch_key_release(key, &event),
+        _ => Some(event),
+    }
+}
+
+/// Dispatches a key press on `(mode, key)`. Esc always means "leave the
+/// current mode" except in `Normal`, where there's nowhere left to go but
+/// out of the program.
+fn dispatch_key_press(key: Key, event: &Event) -> Option<Event> {
+    let mode = INPUT_STATE.lock().unwrap().mode;
+
+    match (mode, key) {
+        (Mode::Hint, Key::Escape) => {
+            INPUT_STATE.lock().unwrap().mode = Mode::Normal;
+            None
+        }
+        (Mode::Hint, _) => {
+            if let Some(ch) = hint_char_for_key(key) {
+                HINT_PREFIX.lock().unwrap().push(ch);
+            }
+            None
+        }
+
+        (Mode::Scroll, Key::Escape) => {
+            INPUT_STATE.lock().unwrap().mode = Mode::Normal;
+            None
+        }
+        (Mode::Scroll, key)
+            if matches!(
+                CONFIG.bindings.get(&key),
+                Some(config::Action::EnterScrollMode) | Some(config::Action::ToggleScrollMode)
+            ) =>
+        {
+            INPUT_STATE.lock().unwrap().mode = Mode::Normal;
+            None
+        }
+        (Mode::Scroll, Key::KeyH) => {
+            send_smooth_scroll(-1.0, 0.0);
+            None
+        }
+        (Mode::Scroll, Key::KeyL) => {
+            send_smooth_scroll(1.0, 0.0);
+            None
+        }
+        (Mode::Scroll, Key::KeyJ) => {
+            send_smooth_scroll(0.0, -1.0);
+            None
+        }
+        (Mode::Scroll, Key::KeyK) => {
+            send_smooth_scroll(0.0, 1.0);
+            None
+        }
+        // Other keys (y/u/b/n, jump grid, ...) have no meaning while
+        // scrolling and are swallowed rather than falling through to Normal.
+        (Mode::Scroll, _) => None,
+
+        // Esc cancels a drag the same way it cancels Hint/Scroll, but it
+        // must also let go of the button - otherwise the OS is left
+        // thinking the mouse is still held down.
+        (Mode::Drag, Key::Escape) => {
+            send(&EventType::ButtonRelease(Button::Left));
+            cancel_move();
+            INPUT_STATE.lock().unwrap().mode = Mode::Normal;
+            None
+        }
+        (Mode::Drag, key) if CONFIG.bindings.get(&key) == Some(&config::Action::EnterDragMode) => {
+            send(&EventType::ButtonRelease(Button::Left));
+            cancel_move();
+            INPUT_STATE.lock().unwrap().mode = Mode::Normal;
+            None
+        }
+        (Mode::Drag, key) if CONFIG.movement.contains_key(&key) => {
+            if let Some(&direction) = CONFIG.movement.get(&key) {
+                begin_move(direction);
+            }
+            None
+        }
+        (Mode::Drag, _) => None,
+
+        /* Movement directions:
+         *
+         *  y  k  u
+         *   ↖ ↑ ↗
+         * h ← . → l
+         *   ↙ ↓ ↘
+         *  b  j  n
+         *
+         * Holding a direction ramps up from the slow speed to a ceiling
+         * instead of moving a fixed step per keypress; releasing it
+         * decelerates back to a stop rather than halting instantly.
+         */
+        (Mode::Normal, key) if CONFIG.movement.contains_key(&key) => {
+            if let Some(&direction) = CONFIG.movement.get(&key) {
+                begin_move(direction);
+                return None;
+            }
+            Some(event.clone())
+        }
+        /* Quick jump to a specific
+         * area on the screen:
+         *  ┌─────┬─────┬─────┬─────┐
+         *  │  Q  │  W  │  E  │  R  │
+         *  ├─────┼─────┼─────┼─────┤
+         *  │  A  │  S  │  D  │  F  │
+         *  ├─────┼─────┼─────┼─────┤
+         *  │  Z  │  X  │  C  │  V  │
+         *  └─────┴─────┴─────┴─────┘
+         */
+        (Mode::Normal, key) if CONFIG.jump_grid.contains_key(&key) => {
+            if let Some((col, row)) = CONFIG.jump_grid.get(&key) {
+                let (x, y) = unsafe {
+                    (
+                        col * SCREEN_WIDTH / 4. + SCREEN_WIDTH / 8.,
+                        row * SCREEN_HEIGHT / 3. + SCREEN_HEIGHT / 6.,
+                    )
                 };
+                let (x, y) = clamp_to_screen(x, y);
+                send(&EventType::MouseMove { x, y });
+                return None;
             }
-            EventType::KeyRelease(key) => {
-                return match key {
-                    Key::Space => {
-                        send(&EventType::ButtonRelease(Button::Left));
-                        return None;
-                    }
-                    Key::ControlLeft | Key::ControlRight | Key::CapsLock => {
-                        send(&EventType::ButtonRelease(Button::Right));
-                        return None;
-                    }
-                    Key::ShiftLeft | Key::ShiftRight | Key::Alt => {
-                        MOUSE_SPEED = FAST_SPEED;
-                        return Some(event);
-                    }
-                    Key::KeyG => {
-                        G_KEY_HELD = false;
-                        return None;
-                    }
-                    _ => Some(event),
-                }
+            Some(event.clone())
+        }
+        // Everything else that isn't a movement direction or a jump-grid
+        // cell is a single-key action, looked up in `CONFIG.bindings`.
+        (Mode::Normal, key) => dispatch_binding(key, event),
+    }
+}
+
+/// Looks up `key` in `CONFIG.bindings` and performs the bound action.
+/// Keys with no binding fall through unmodified, like any other key the
+/// tool doesn't care about.
+fn dispatch_binding(key: Key, event: &Event) -> Option<Event> {
+    let Some(&action) = CONFIG.bindings.get(&key) else {
+        return Some(event.clone());
+    };
+
+    match action {
+        config::Action::LeftClick => {
+            send(&EventType::ButtonPress(Button::Left));
+            None
+        }
+        config::Action::RightClick => {
+            send(&EventType::ButtonPress(Button::Right));
+            None
+        }
+        config::Action::EnterScrollMode | config::Action::ToggleScrollMode => {
+            cancel_move();
+            INPUT_STATE.lock().unwrap().mode = Mode::Scroll;
+            None
+        }
+        config::Action::EnterHintMode => {
+            cancel_move();
+            if let Some(tx) = HINT_REQUEST_TX.lock().unwrap().as_ref() {
+                let _ = tx.send(());
             }
-            _ => Some(event),
-        };
+            None
+        }
+        // `d` is already a jump-grid cell, so press-and-hold drag defaults
+        // to `p`. It presses and holds the left button, hjkl/yubn move the
+        // cursor while it stays down, and `p` again (or Esc) releases it.
+        config::Action::EnterDragMode => {
+            cancel_move();
+            send(&EventType::ButtonPress(Button::Left));
+            INPUT_STATE.lock().unwrap().mode = Mode::Drag;
+            None
+        }
+        config::Action::SlowSpeed | config::Action::UltraFastSpeed => {
+            INPUT_STATE.lock().unwrap().held_modifiers.insert(key);
+            Some(event.clone())
+        }
+        config::Action::DetectElements => {
+            thread::spawn(print_clickable_elements);
+            None
+        }
+        config::Action::Exit => std::process::exit(0),
+    }
+}
+
+fn dispatch_key_release(key: Key, event: &Event) -> Option<Event> {
+    if CONFIG.movement.contains_key(&key) {
+        end_move();
+        return Some(event.clone());
+    }
+
+    match CONFIG.bindings.get(&key) {
+        Some(config::Action::LeftClick) => {
+            send(&EventType::ButtonRelease(Button::Left));
+            None
+        }
+        Some(config::Action::RightClick) => {
+            send(&EventType::ButtonRelease(Button::Right));
+            None
+        }
+        Some(config::Action::SlowSpeed) | Some(config::Action::UltraFastSpeed) => {
+            INPUT_STATE.lock().unwrap().held_modifiers.remove(&key);
+            Some(event.clone())
+        }
+        _ => Some(event.clone()),
     }
 }
 
@@ -686,6 +1852,10 @@ fn main() {
         "Unknown"
     };
 
+    // Force config loading now so a parse error is reported up front,
+    // rather than silently falling back the first time a key is pressed.
+    lazy_static::initialize(&CONFIG);
+
     println!("🐭 Vimouse - Vim-like Mouse Control ({})", platform);
     println!("Press 'i' to find clickable elements on screen");
     println!("Press 'Esc' to exit");
@@ -695,15 +1865,11 @@ fn main() {
         unsafe {
             SCREEN_WIDTH = w as f64;
             SCREEN_HEIGHT = h as f64;
-
-            // Get current mouse position instead of defaulting to center
-            if let Some(current_pos) = get_current_mouse_position() {
-                MOUSE_POSITION = current_pos;
-            } else {
-                // Fallback to center if we can't get current position
-                MOUSE_POSITION = (SCREEN_WIDTH / 2., SCREEN_HEIGHT / 2.);
-            }
         }
+
+        // Get current mouse position instead of defaulting to center
+        let position = get_current_mouse_position().unwrap_or((w as f64 / 2., h as f64 / 2.));
+        INPUT_STATE.lock().unwrap().position = position;
         println!("Screen size: {}x{}", w, h);
     }
 
@@ -713,7 +1879,10 @@ fn main() {
     println!("   Speed: Shift (slow), Alt (fast)");
     println!("   Scroll: g+hjkl, t (toggle)");
     println!("   Detect: i (find clickable elements)");
+    println!("   Hints: o (label clickable elements, type to click, Esc to cancel)");
+    println!("   Drag: p (press and hold), hjkl/yubn to move, p or Esc to drop");
     println!("   Exit: Esc");
+    println!("\nKeybindings and speeds can be customized in ~/.config/vimouse/config.toml");
 
     if cfg!(target_os = "macos") {
         println!("\n⚠️  Note: You may need to grant accessibility permissions in System Preferences.");
@@ -721,14 +1890,28 @@ fn main() {
     
     println!("Starting mouse control...\n");
 
-    if let Err(error) = grab(callback) {
-        println!("ERROR: {error:?}");
-        if cfg!(target_os = "macos") {
-            println!("\n💡 Troubleshooting:");
-            println!("   1. Go to System Preferences > Security & Privacy > Privacy");
-            println!("   2. Select 'Accessibility' from the left panel");
-            println!("   3. Add this application to the list");
-            println!("   4. Make sure the checkbox is enabled");
+    // `grab` wants to own whichever thread calls it, and `winit` (used by
+    // the hint overlay) requires its `EventLoop` to be created on the
+    // thread the OS considers "main". Run `grab` on a background thread
+    // and keep this one free to host the overlay loop whenever the grab
+    // callback asks for a hint session.
+    let (hint_tx, hint_rx) = mpsc::channel::<()>();
+    *HINT_REQUEST_TX.lock().unwrap() = Some(hint_tx);
+
+    thread::spawn(move || {
+        if let Err(error) = grab(callback) {
+            println!("ERROR: {error:?}");
+            if cfg!(target_os = "macos") {
+                println!("\n💡 Troubleshooting:");
+                println!("   1. Go to System Preferences > Security & Privacy > Privacy");
+                println!("   2. Select 'Accessibility' from the left panel");
+                println!("   3. Add this application to the list");
+                println!("   4. Make sure the checkbox is enabled");
+            }
         }
+    });
+
+    for () in hint_rx {
+        enter_hint_mode();
     }
 }